@@ -11,6 +11,8 @@ pub enum RttmError {
     ParseFloatError(std::num::ParseFloatError),
     /// Parse string to integer error.
     ParseIntError(std::num::ParseIntError),
+    /// Malformed input while converting to or from another annotation format.
+    FormatError(String),
 }
 
 impl std::error::Error for RttmError {}
@@ -21,6 +23,7 @@ impl fmt::Display for RttmError {
             Self::IOError(err) => write!(f, "IO error: {err}"),
             Self::ParseFloatError(err) => write!(f, "Float parse error: {err}"),
             Self::ParseIntError(err) => write!(f, "Integer parse error: {err}"),
+            Self::FormatError(msg) => write!(f, "Format conversion error: {msg}"),
         }
     }
 }
@@ -0,0 +1,363 @@
+//! Conversion between `Rttm` and other time-marked annotation formats.
+//!
+//! Diarization output frequently has to move into audio editors or subtitle
+//! tools, so `Rttm` doubles as an interchange hub. The Audacity, subtitle and
+//! TextGrid converters funnel through [`TimedSegment`], a format-neutral
+//! labelled interval, which keeps those `from_*`/`to_*` functions small and
+//! symmetric. The CTM converters map `RttmSegment` fields directly, so that
+//! the file and channel IDs carry over.
+//!
+//! Supported formats:
+//! - NIST CTM (`<file> <channel> <onset> <duration> <label>`)
+//! - Audacity label tracks (`start\tend\tlabel`)
+//! - WebVTT and SubRip (SRT) subtitle cues
+//! - Praat TextGrid interval tiers, one tier per speaker
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use crate::{Rttm, RttmError, RttmSegment};
+
+/// A format-neutral timed annotation: a labelled `[start, end)` interval in
+/// seconds. Used as the common representation when converting between `Rttm`
+/// and the other annotation formats in this module.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimedSegment {
+    /// Interval onset in seconds from the beginning of the recording.
+    pub start: f64,
+    /// Interval offset in seconds from the beginning of the recording.
+    pub end: f64,
+    /// Label, typically a speaker name.
+    pub label: String,
+}
+
+impl TimedSegment {
+    /// Create a new `TimedSegment`.
+    pub fn new(start: f64, end: f64, label: &str) -> Self {
+        Self { start, end, label: label.to_owned() }
+    }
+
+    /// Returns the interval duration in seconds.
+    pub fn duration(&self) -> f64 {
+        self.end - self.start
+    }
+}
+
+impl From<&RttmSegment> for TimedSegment {
+    fn from(segment: &RttmSegment) -> Self {
+        let (start, end) = segment.timespan();
+        Self { start, end, label: segment.speaker_name.clone() }
+    }
+}
+
+impl Rttm {
+    /// Returns every segment as a format-neutral [`TimedSegment`].
+    pub fn to_timed(&self) -> Vec<TimedSegment> {
+        self.iter().map(TimedSegment::from).collect()
+    }
+
+    /// Builds an `Rttm` from format-neutral intervals, stamping each segment
+    /// with `file_id` and the standard `SPEAKER`/`<NA>` field values.
+    pub fn from_timed(segments: &[TimedSegment], file_id: &str) -> Self {
+        let segments = segments.iter()
+            .map(|seg| timed_to_rttm(seg, file_id))
+            .collect::<Vec<_>>();
+        Self::from_segments(segments)
+    }
+}
+
+/// Maps a [`TimedSegment`] onto a standard-conforming `RttmSegment`.
+fn timed_to_rttm(seg: &TimedSegment, file_id: &str) -> RttmSegment {
+    RttmSegment {
+        segment_type: "SPEAKER".to_owned(),
+        file_id: file_id.to_owned(),
+        channel_id: 1,
+        turn_onset: seg.start,
+        turn_duration: seg.duration(),
+        orthography_field: "<NA>".to_owned(),
+        speaker_type: "<NA>".to_owned(),
+        speaker_name: seg.label.clone(),
+        confidence_score: "<NA>".to_owned(),
+        signal_lookahead_time: "<NA>".to_owned(),
+    }
+}
+
+// -------------------------------------------------------------------------
+// NIST CTM
+// -------------------------------------------------------------------------
+
+/// Serialises `rttm` as NIST CTM rows:
+/// `<file_id> <channel> <onset> <duration> <label>`.
+pub fn to_ctm(rttm: &Rttm) -> String {
+    rttm.iter()
+        .map(|seg| format!("{} {} {} {} {}",
+            seg.file_id,
+            seg.channel_id,
+            seg.turn_onset,
+            seg.turn_duration,
+            seg.speaker_name,
+        ))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parses NIST CTM rows into an `Rttm`. The onset and duration fields are
+/// taken verbatim; the fifth field becomes the speaker name.
+pub fn from_ctm(text: &str) -> Result<Rttm, RttmError> {
+    let mut segments = Vec::new();
+    for line in text.lines().filter(|l| !l.trim().is_empty()) {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        if fields.len() < 5 {
+            return Err(RttmError::FormatError(
+                format!("expected at least 5 CTM fields, got {}", fields.len())));
+        }
+        let file_id = fields[0];
+        let channel_id = fields[1].parse::<usize>()?;
+        let onset = fields[2].parse::<f64>()?;
+        let duration = fields[3].parse::<f64>()?;
+        let label = fields[4];
+        segments.push(RttmSegment {
+            segment_type: "SPEAKER".to_owned(),
+            file_id: file_id.to_owned(),
+            channel_id,
+            turn_onset: onset,
+            turn_duration: duration,
+            orthography_field: "<NA>".to_owned(),
+            speaker_type: "<NA>".to_owned(),
+            speaker_name: label.to_owned(),
+            confidence_score: "<NA>".to_owned(),
+            signal_lookahead_time: "<NA>".to_owned(),
+        });
+    }
+    Ok(Rttm::from_segments(segments))
+}
+
+// -------------------------------------------------------------------------
+// Audacity label tracks
+// -------------------------------------------------------------------------
+
+/// Serialises `rttm` as an Audacity label track: `start\tend\tlabel`.
+pub fn to_audacity(rttm: &Rttm) -> String {
+    rttm.to_timed()
+        .iter()
+        .map(|seg| format!("{}\t{}\t{}", seg.start, seg.end, seg.label))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parses an Audacity label track (`start\tend\tlabel`) into an `Rttm`,
+/// stamping every segment with `file_id`.
+pub fn from_audacity(text: &str, file_id: &str) -> Result<Rttm, RttmError> {
+    let mut timed = Vec::new();
+    for line in text.lines().filter(|l| !l.trim().is_empty()) {
+        let fields = line.splitn(3, '\t').collect::<Vec<_>>();
+        if fields.len() < 3 {
+            return Err(RttmError::FormatError(
+                format!("expected 3 tab-separated Audacity fields, got {}", fields.len())));
+        }
+        let start = fields[0].trim().parse::<f64>()?;
+        let end = fields[1].trim().parse::<f64>()?;
+        timed.push(TimedSegment::new(start, end, fields[2].trim()));
+    }
+    Ok(Rttm::from_timed(&timed, file_id))
+}
+
+// -------------------------------------------------------------------------
+// WebVTT / SRT subtitle cues
+// -------------------------------------------------------------------------
+
+/// Serialises `rttm` as WebVTT cues, one cue per turn.
+pub fn to_webvtt(rttm: &Rttm) -> String {
+    let mut out = String::from("WEBVTT\n");
+    for (i, seg) in rttm.to_timed().iter().enumerate() {
+        let _ = write!(out, "\n{}\n{} --> {}\n{}\n",
+            i + 1,
+            format_timestamp(seg.start, '.'),
+            format_timestamp(seg.end, '.'),
+            seg.label,
+        );
+    }
+    out
+}
+
+/// Serialises `rttm` as SubRip (SRT) cues, one cue per turn.
+pub fn to_srt(rttm: &Rttm) -> String {
+    let mut out = String::new();
+    for (i, seg) in rttm.to_timed().iter().enumerate() {
+        let _ = write!(out, "{}\n{} --> {}\n{}\n\n",
+            i + 1,
+            format_timestamp(seg.start, ','),
+            format_timestamp(seg.end, ','),
+            seg.label,
+        );
+    }
+    out
+}
+
+/// Parses WebVTT or SRT cues into an `Rttm`, stamping every segment with
+/// `file_id`. The cue payload becomes the speaker name; cue indices and the
+/// `WEBVTT` header are ignored.
+pub fn from_subtitles(text: &str, file_id: &str) -> Result<Rttm, RttmError> {
+    let mut timed = Vec::new();
+    let mut pending: Option<(f64, f64)> = None;
+    let mut label = String::new();
+
+    let flush = |timed: &mut Vec<TimedSegment>, span: &mut Option<(f64, f64)>, label: &mut String| {
+        if let Some((start, end)) = span.take() {
+            timed.push(TimedSegment::new(start, end, label.trim()));
+        }
+        label.clear();
+    };
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            flush(&mut timed, &mut pending, &mut label);
+            continue;
+        }
+        if trimmed == "WEBVTT" || trimmed.starts_with("NOTE") {
+            continue;
+        }
+        if let Some((lhs, rhs)) = trimmed.split_once("-->") {
+            let start = parse_timestamp(lhs.trim())?;
+            // Some WebVTT cues append positioning settings after the end time.
+            let end_field = rhs.trim().split_whitespace().next().unwrap_or("");
+            let end = parse_timestamp(end_field)?;
+            pending = Some((start, end));
+            continue;
+        }
+        // A bare integer on its own line is a cue index; skip it.
+        if pending.is_some() && trimmed.parse::<usize>().is_err() {
+            if !label.is_empty() {
+                label.push(' ');
+            }
+            label.push_str(trimmed);
+        }
+    }
+    flush(&mut timed, &mut pending, &mut label);
+
+    Ok(Rttm::from_timed(&timed, file_id))
+}
+
+/// Formats `seconds` as `HH:MM:SS<sep>mmm`, using `sep` to separate the
+/// fractional milliseconds (`.` for WebVTT, `,` for SRT).
+fn format_timestamp(seconds: f64, sep: char) -> String {
+    let total_ms = (seconds * 1000.0).round() as i64;
+    let ms = total_ms % 1000;
+    let total_secs = total_ms / 1000;
+    let s = total_secs % 60;
+    let m = (total_secs / 60) % 60;
+    let h = total_secs / 3600;
+    format!("{h:02}:{m:02}:{s:02}{sep}{ms:03}")
+}
+
+/// Parses a `HH:MM:SS.mmm` or `MM:SS.mmm` timestamp (either `.` or `,` as the
+/// millisecond separator) into seconds.
+fn parse_timestamp(value: &str) -> Result<f64, RttmError> {
+    let normalised = value.replace(',', ".");
+    let parts = normalised.split(':').collect::<Vec<_>>();
+    let (h, m, s) = match parts.as_slice() {
+        [h, m, s] => (h.parse::<f64>()?, m.parse::<f64>()?, s.parse::<f64>()?),
+        [m, s] => (0.0, m.parse::<f64>()?, s.parse::<f64>()?),
+        _ => return Err(RttmError::FormatError(
+            format!("malformed timestamp: {value}"))),
+    };
+    Ok(h * 3600.0 + m * 60.0 + s)
+}
+
+// -------------------------------------------------------------------------
+// Praat TextGrid
+// -------------------------------------------------------------------------
+
+/// Serialises `rttm` as a Praat TextGrid with one `IntervalTier` per speaker.
+/// Each tier alternates silence intervals (empty text) with the speaker's
+/// turns so the tiers remain contiguous from 0 to the file end.
+pub fn to_textgrid(rttm: &Rttm) -> String {
+    let xmax = rttm.iter()
+        .map(|seg| seg.timespan().1)
+        .fold(0.0_f64, f64::max);
+
+    // Group turns per speaker, preserving a stable (sorted) tier order.
+    let mut tiers: BTreeMap<&str, Vec<&RttmSegment>> = BTreeMap::new();
+    for seg in rttm.iter() {
+        tiers.entry(seg.speaker_name.as_str()).or_default().push(seg);
+    }
+
+    let mut out = String::new();
+    out.push_str("File type = \"ooTextFile\"\n");
+    out.push_str("Object class = \"TextGrid\"\n\n");
+    let _ = write!(out, "xmin = 0\nxmax = {xmax}\n");
+    out.push_str("tiers? <exists>\n");
+    let _ = write!(out, "size = {}\n", tiers.len());
+    out.push_str("item []:\n");
+
+    for (tier_idx, (speaker, segments)) in tiers.iter().enumerate() {
+        let mut sorted = segments.clone();
+        sorted.sort_by(|a, b| a.turn_onset.total_cmp(&b.turn_onset));
+
+        // Build contiguous intervals, inserting silence between turns.
+        let mut intervals: Vec<(f64, f64, &str)> = Vec::new();
+        let mut cursor = 0.0_f64;
+        for seg in sorted {
+            let (start, end) = seg.timespan();
+            if start > cursor {
+                intervals.push((cursor, start, ""));
+            }
+            intervals.push((start, end, speaker));
+            cursor = end;
+        }
+        if cursor < xmax {
+            intervals.push((cursor, xmax, ""));
+        }
+
+        let _ = write!(out, "    item [{}]:\n", tier_idx + 1);
+        out.push_str("        class = \"IntervalTier\"\n");
+        let _ = write!(out, "        name = \"{speaker}\"\n");
+        let _ = write!(out, "        xmin = 0\n        xmax = {xmax}\n");
+        let _ = write!(out, "        intervals: size = {}\n", intervals.len());
+        for (i, (xmin, xmax, text)) in intervals.iter().enumerate() {
+            let _ = write!(out, "        intervals [{}]:\n", i + 1);
+            let _ = write!(out, "            xmin = {xmin}\n");
+            let _ = write!(out, "            xmax = {xmax}\n");
+            let _ = write!(out, "            text = \"{text}\"\n");
+        }
+    }
+
+    out
+}
+
+/// Parses a Praat TextGrid into an `Rttm`, stamping every segment with
+/// `file_id`. Each non-empty interval becomes a turn whose speaker name is the
+/// tier name; empty (silence) intervals are skipped.
+pub fn from_textgrid(text: &str, file_id: &str) -> Result<Rttm, RttmError> {
+    let mut timed = Vec::new();
+    let mut tier_name = String::new();
+    let mut xmin: Option<f64> = None;
+    let mut xmax: Option<f64> = None;
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if let Some(value) = trimmed.strip_prefix("name =") {
+            tier_name = value.trim().trim_matches('"').to_owned();
+        } else if let Some(value) = trimmed.strip_prefix("xmin =") {
+            xmin = Some(value.trim().parse::<f64>()?);
+        } else if let Some(value) = trimmed.strip_prefix("xmax =") {
+            xmax = Some(value.trim().parse::<f64>()?);
+        } else if let Some(value) = trimmed.strip_prefix("text =") {
+            let label = value.trim().trim_matches('"');
+            // The file-level xmin/xmax precede any tier; only emit once we
+            // have a named tier and a non-empty label.
+            if let (Some(start), Some(end)) = (xmin, xmax) {
+                if !label.is_empty() && !tier_name.is_empty() {
+                    timed.push(TimedSegment::new(start, end, &tier_name));
+                }
+            }
+            xmin = None;
+            xmax = None;
+        }
+    }
+
+    timed.sort_by(|a, b| a.start.total_cmp(&b.start));
+    Ok(Rttm::from_timed(&timed, file_id))
+}
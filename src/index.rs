@@ -0,0 +1,130 @@
+//! An augmented interval tree over the turns of an `Rttm` for fast
+//! point ("who is speaking at time *t*") and range queries.
+//!
+//! The index borrows the segments from the owning [`Rttm`], so no turn data is
+//! duplicated. Each node stores a turn's `[onset, offset)` interval in
+//! milliseconds plus the maximum offset across its subtree, so a stabbing
+//! query descends only into subtrees whose maximum offset reaches the query
+//! point.
+//!
+//! Build cost is `O(n log n)` (sorting by onset, then a balanced build);
+//! each query costs `O(log n + k)` for `k` reported segments.
+
+use crate::{Rttm, RttmSegment};
+
+/// A single node of the augmented interval tree.
+struct Node {
+    /// Index of the segment in the borrowed slice.
+    seg: usize,
+    /// Interval onset in milliseconds.
+    start: i64,
+    /// Interval offset in milliseconds.
+    end: i64,
+    /// Maximum offset (ms) across this node's subtree.
+    max_end: i64,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+/// An interval index built from an `Rttm`. Borrows its segments.
+pub struct RttmIndex<'a> {
+    segments: &'a [RttmSegment],
+    nodes: Vec<Node>,
+    root: Option<usize>,
+}
+
+impl Rttm {
+    /// Builds an [`RttmIndex`] borrowing this `Rttm`'s segments.
+    pub fn index(&self) -> RttmIndex<'_> {
+        RttmIndex::new(self.segments())
+    }
+}
+
+impl<'a> RttmIndex<'a> {
+    /// Builds the index from a slice of segments in `O(n log n)`.
+    pub fn new(segments: &'a [RttmSegment]) -> Self {
+        let mut order = (0..segments.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| segments[i].timespan_ms().0);
+
+        let mut index = Self { segments, nodes: Vec::with_capacity(segments.len()), root: None };
+        index.root = index.build(segments, &order);
+        index
+    }
+
+    /// Recursively builds a balanced subtree from the onset-sorted slice,
+    /// returning the node index of its root and propagating `max_end` upwards.
+    fn build(&mut self, segments: &[RttmSegment], order: &[usize]) -> Option<usize> {
+        if order.is_empty() {
+            return None;
+        }
+        let mid = order.len() / 2;
+        let seg = order[mid];
+        let (start, end) = segments[seg].timespan_ms();
+
+        let left = self.build(segments, &order[..mid]);
+        let right = self.build(segments, &order[mid + 1..]);
+
+        let mut max_end = end;
+        if let Some(l) = left {
+            max_end = max_end.max(self.nodes[l].max_end);
+        }
+        if let Some(r) = right {
+            max_end = max_end.max(self.nodes[r].max_end);
+        }
+
+        self.nodes.push(Node { seg, start, end, max_end, left, right });
+        Some(self.nodes.len() - 1)
+    }
+
+    /// Returns every segment whose turn contains the instant `t` (seconds),
+    /// i.e. `onset <= t < offset`, in `O(log n + k)`.
+    pub fn speakers_at(&self, t: f64) -> Vec<&'a RttmSegment> {
+        let t_ms = (t * 1000.0).round() as i64;
+        let mut out = Vec::new();
+        self.stab(self.root, t_ms, &mut out);
+        out
+    }
+
+    fn stab(&self, node: Option<usize>, t: i64, out: &mut Vec<&'a RttmSegment>) {
+        let Some(idx) = node else { return };
+        let node = &self.nodes[idx];
+        // Prune: no interval in this subtree reaches past `t`.
+        if node.max_end <= t {
+            return;
+        }
+        self.stab(node.left, t, out);
+        if node.start <= t {
+            if t < node.end {
+                out.push(&self.segments[node.seg]);
+            }
+            // Right subtree holds larger onsets that may still cover `t`.
+            self.stab(node.right, t, out);
+        }
+    }
+
+    /// Returns every segment whose turn intersects the half-open range
+    /// `[start, end)` (seconds), in `O(log n + k)`.
+    pub fn segments_in(&self, start: f64, end: f64) -> Vec<&'a RttmSegment> {
+        let start_ms = (start * 1000.0).round() as i64;
+        let end_ms = (end * 1000.0).round() as i64;
+        let mut out = Vec::new();
+        self.range(self.root, start_ms, end_ms, &mut out);
+        out
+    }
+
+    fn range(&self, node: Option<usize>, qs: i64, qe: i64, out: &mut Vec<&'a RttmSegment>) {
+        let Some(idx) = node else { return };
+        let node = &self.nodes[idx];
+        // Prune: every interval in this subtree ends at or before the range.
+        if node.max_end <= qs {
+            return;
+        }
+        self.range(node.left, qs, qe, out);
+        if node.start < qe {
+            if node.end > qs {
+                out.push(&self.segments[node.seg]);
+            }
+            self.range(node.right, qs, qe, out);
+        }
+    }
+}
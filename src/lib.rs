@@ -9,7 +9,16 @@
 pub mod rttm;
 pub mod segment;
 pub mod errors;
+pub mod format;
+pub mod stats;
+pub mod timeline;
+pub mod score;
+pub mod index;
 
 pub use rttm::Rttm;
 pub use segment::RttmSegment;
-pub use errors::RttmError;
\ No newline at end of file
+pub use errors::RttmError;
+pub use format::TimedSegment;
+pub use stats::{RttmStats, SpeakerStats};
+pub use score::{score, DerReport};
+pub use index::RttmIndex;
\ No newline at end of file
@@ -1,14 +1,15 @@
-use std::{path::Path, fs::File, io::{BufReader, BufRead, Write}, collections::HashSet};
+use std::{path::Path, fs::File, io::{BufReader, BufRead, Write}, collections::HashSet, fmt, str::FromStr};
 
 use crate::{RttmError, RttmSegment};
 
 /// Rich Transcription Time Marked (RTTM) file format.
-/// 
+///
 /// References:
 /// - <https://web.archive.org/web/20170119114252/http://www.itl.nist.gov/iad/mig/tests/rt/2009/docs/rt09-meeting-eval-plan-v2.pdf>
 /// - <https://catalog.ldc.upenn.edu/docs/LDC2004T12/RTTM-format-v13.pdf>
 /// - <https://stackoverflow.com/questions/30975084/rttm-file-format>
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rttm(Vec<RttmSegment>);
 
 impl Rttm {
@@ -29,7 +30,7 @@ impl Rttm {
                 line_result?
             };
 
-            let segment = RttmSegment::from_str(&line)?;
+            let segment = line.parse::<RttmSegment>()?;
 
             segments.push(segment);
         };
@@ -37,6 +38,11 @@ impl Rttm {
         Ok(Self(segments))
     }
 
+    /// Construct an `Rttm` from an owned list of segments.
+    pub fn from_segments(segments: Vec<RttmSegment>) -> Self {
+        Self(segments)
+    }
+
     /// Returns a reference to contained segments.
     pub fn segments(&self) -> &[RttmSegment] {
         &self.0
@@ -75,15 +81,6 @@ impl Rttm {
         Ok(())
     }
 
-    /// Returns `Rttm` as a string that conforms to the standard,
-    /// for writing to file.
-    pub fn to_string(&self) -> String {
-        self.iter()
-            .map(|seg| seg.to_string())
-            .collect::<Vec<_>>()
-            .join("\n")
-    }
-
     /// Iterate over segments.
     pub fn iter(&self) -> impl Iterator<Item = &RttmSegment> {
         self.0.iter()
@@ -155,3 +152,28 @@ impl Rttm {
             .sum()
     }
 }
+
+/// Parses an entire RTTM document, one segment per non-empty line.
+impl FromStr for Rttm {
+    type Err = RttmError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let segments = value.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.parse::<RttmSegment>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self(segments))
+    }
+}
+
+/// Writes every segment on its own line, conforming to the standard.
+impl fmt::Display for Rttm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let string = self.iter()
+            .map(|seg| seg.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        f.write_str(&string)
+    }
+}
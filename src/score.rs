@@ -0,0 +1,270 @@
+//! Diarization Error Rate (DER) scoring between a reference and a hypothesis
+//! `Rttm`, following the decomposition used by `dscore`/`md-eval`:
+//!
+//! ```text
+//! DER = (missed + false_alarm + speaker_error) / total_reference_speech
+//! ```
+//!
+//! The timeline of both files is split into contiguous regions at every
+//! segment boundary; within each region the number of active reference and
+//! hypothesis speakers is constant. Labels are mapped one-to-one by the
+//! assignment that maximises mapped overlap (the Hungarian algorithm on the
+//! negated co-occurrence matrix), and the three error components are
+//! accumulated per region.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::Rttm;
+
+/// Breakdown of a DER computation. All durations are in seconds.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DerReport {
+    /// Reference speech in excess of the active hypothesis speakers, i.e.
+    /// with no hypothesis speaker at all to cover it.
+    pub missed_speech: f64,
+    /// Hypothesis speech with no corresponding reference speaker.
+    pub false_alarm: f64,
+    /// Speech attributed to the wrong (confused) mapped speaker.
+    pub speaker_error: f64,
+    /// Total reference speech over the scored regions.
+    pub total_reference: f64,
+    /// Final DER as a percentage of `total_reference`.
+    pub der_percent: f64,
+    /// Reference-to-hypothesis speaker mapping chosen by the optimiser.
+    pub mapping: BTreeMap<String, String>,
+}
+
+impl fmt::Display for DerReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "missed speech    : {:.3}s", self.missed_speech)?;
+        writeln!(f, "false alarm      : {:.3}s", self.false_alarm)?;
+        writeln!(f, "speaker error    : {:.3}s", self.speaker_error)?;
+        writeln!(f, "reference speech : {:.3}s", self.total_reference)?;
+        write!(f, "DER              : {:.2}%", self.der_percent)
+    }
+}
+
+/// Scores `hypothesis` against `reference`, returning a [`DerReport`].
+///
+/// `collar` discards regions within ±`collar` seconds of a reference segment
+/// boundary (forgiveness collar). When `ignore_overlap` is set, regions where
+/// more than one reference speaker is active are skipped entirely.
+pub fn score(reference: &Rttm, hypothesis: &Rttm, collar: f64, ignore_overlap: bool) -> DerReport {
+    let ref_speakers = reference.speakers();
+    let hyp_speakers = hypothesis.speakers();
+
+    // Every boundary time, in milliseconds, from both files.
+    let mut boundaries: BTreeSet<i64> = BTreeSet::new();
+    for seg in reference.iter().chain(hypothesis.iter()) {
+        let (start, end) = seg.timespan_ms();
+        boundaries.insert(start);
+        boundaries.insert(end);
+    }
+
+    // Reference boundaries in seconds, for the collar test.
+    let ref_boundaries = reference.iter()
+        .flat_map(|seg| {
+            let (s, e) = seg.timespan();
+            [s, e]
+        })
+        .collect::<Vec<_>>();
+    let collar_ms = (collar * 1000.0).round() as i64;
+
+    // Co-occurrence overlap matrix (reference rows × hypothesis columns).
+    let mut overlap = vec![vec![0.0_f64; hyp_speakers.len()]; ref_speakers.len()];
+
+    // Region records retained for the second pass once the mapping is known.
+    struct Region {
+        duration: f64,
+        ref_active: Vec<usize>,
+        hyp_active: Vec<usize>,
+    }
+    let mut regions: Vec<Region> = Vec::new();
+
+    let bounds = boundaries.iter().cloned().collect::<Vec<_>>();
+    for win in bounds.windows(2) {
+        let (a, b) = (win[0], win[1]);
+        if b <= a {
+            continue;
+        }
+        let mid = (a + b) / 2;
+        let duration = (b - a) as f64 / 1000.0;
+
+        // Collar: drop the region if its centre lies within the forgiveness
+        // window of any reference boundary.
+        if collar_ms > 0 && ref_boundaries.iter()
+            .any(|&t| ((t * 1000.0).round() as i64 - mid).abs() <= collar_ms)
+        {
+            continue;
+        }
+
+        let ref_active = ref_speakers.iter().enumerate()
+            .filter(|(_, name)| speaker_active(reference, name, mid))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        if ignore_overlap && ref_active.len() > 1 {
+            continue;
+        }
+
+        let hyp_active = hyp_speakers.iter().enumerate()
+            .filter(|(_, name)| speaker_active(hypothesis, name, mid))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        for &r in &ref_active {
+            for &h in &hyp_active {
+                overlap[r][h] += duration;
+            }
+        }
+
+        regions.push(Region { duration, ref_active, hyp_active });
+    }
+
+    // Optimal one-to-one mapping maximising mapped overlap.
+    let assignment = hungarian_max(&overlap);
+    let mut ref_to_hyp_idx: BTreeMap<usize, usize> = BTreeMap::new();
+    let mut mapping = BTreeMap::new();
+    for (r, &h) in assignment.iter().enumerate() {
+        if let Some(h) = h {
+            if overlap[r][h] > 0.0 {
+                ref_to_hyp_idx.insert(r, h);
+                mapping.insert(ref_speakers[r].to_owned(), hyp_speakers[h].to_owned());
+            }
+        }
+    }
+
+    let mut missed = 0.0;
+    let mut false_alarm = 0.0;
+    let mut speaker_error = 0.0;
+    let mut total_reference = 0.0;
+    for region in &regions {
+        let n_ref = region.ref_active.len();
+        let n_hyp = region.hyp_active.len();
+        let n_correct = region.ref_active.iter()
+            .filter(|&&r| ref_to_hyp_idx.get(&r)
+                .is_some_and(|h| region.hyp_active.contains(h)))
+            .count();
+
+        missed += region.duration * n_ref.saturating_sub(n_hyp) as f64;
+        false_alarm += region.duration * n_hyp.saturating_sub(n_ref) as f64;
+        speaker_error += region.duration * (n_ref.min(n_hyp) - n_correct) as f64;
+        total_reference += region.duration * n_ref as f64;
+    }
+
+    let der_percent = if total_reference > 0.0 {
+        100.0 * (missed + false_alarm + speaker_error) / total_reference
+    } else {
+        0.0
+    };
+
+    DerReport {
+        missed_speech: missed,
+        false_alarm,
+        speaker_error,
+        total_reference,
+        der_percent,
+        mapping,
+    }
+}
+
+/// Returns `true` if `speaker` has a turn containing the millisecond instant
+/// `t` (half-open `[onset, offset)`).
+fn speaker_active(rttm: &Rttm, speaker: &str, t: i64) -> bool {
+    rttm.iter().any(|seg| {
+        if seg.speaker_name != speaker {
+            return false;
+        }
+        let (start, end) = seg.timespan_ms();
+        start <= t && t < end
+    })
+}
+
+/// Solves the rectangular assignment problem, maximising the total of the
+/// selected entries. Returns `assignment[r] = Some(c)` when reference row `r`
+/// is matched to hypothesis column `c`, or `None` when it is left unmatched.
+///
+/// Implemented as the `O(n^3)` Hungarian algorithm on the negated matrix,
+/// padded to a square with zero-cost dummy rows/columns.
+fn hungarian_max(matrix: &[Vec<f64>]) -> Vec<Option<usize>> {
+    let rows = matrix.len();
+    let cols = matrix.first().map_or(0, |r| r.len());
+    if rows == 0 || cols == 0 {
+        return vec![None; rows];
+    }
+    let n = rows.max(cols);
+
+    // Square cost matrix: negate so that maximising overlap becomes minimising
+    // cost. Dummy cells cost 0.
+    let mut cost = vec![vec![0.0_f64; n]; n];
+    for (r, row) in matrix.iter().enumerate() {
+        for (c, &v) in row.iter().enumerate() {
+            cost[r][c] = -v;
+        }
+    }
+
+    // Potentials method (1-indexed working arrays).
+    let inf = f64::INFINITY;
+    let mut u = vec![0.0_f64; n + 1];
+    let mut v = vec![0.0_f64; n + 1];
+    let mut p = vec![0_usize; n + 1]; // p[col] = row assigned to col
+    let mut way = vec![0_usize; n + 1];
+
+    for i in 1..=n {
+        p[0] = i;
+        let mut j0 = 0;
+        let mut minv = vec![inf; n + 1];
+        let mut used = vec![false; n + 1];
+        loop {
+            used[j0] = true;
+            let i0 = p[j0];
+            let mut delta = inf;
+            let mut j1 = 0;
+            for j in 1..=n {
+                if !used[j] {
+                    let cur = cost[i0 - 1][j - 1] - u[i0] - v[j];
+                    if cur < minv[j] {
+                        minv[j] = cur;
+                        way[j] = j0;
+                    }
+                    if minv[j] < delta {
+                        delta = minv[j];
+                        j1 = j;
+                    }
+                }
+            }
+            for j in 0..=n {
+                if used[j] {
+                    u[p[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    minv[j] -= delta;
+                }
+            }
+            j0 = j1;
+            if p[j0] == 0 {
+                break;
+            }
+        }
+        loop {
+            let j1 = way[j0];
+            p[j0] = p[j1];
+            j0 = j1;
+            if j0 == 0 {
+                break;
+            }
+        }
+    }
+
+    // p[col] = row (1-indexed). Invert into row -> col, keeping only real cells.
+    let mut assignment = vec![None; rows];
+    for j in 1..=n {
+        let i = p[j];
+        if i >= 1 && i <= rows && j <= cols {
+            assignment[i - 1] = Some(j - 1);
+        }
+    }
+    assignment
+}
@@ -1,9 +1,12 @@
+use std::fmt;
+use std::str::FromStr;
 use std::time::Duration;
 
 use crate::RttmError;
 
 /// A single row in an RTTM file. Delimiter is a single space.
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RttmSegment {
     /// Type, segment type; should always be SPEAKER
     pub segment_type: String,
@@ -28,8 +31,35 @@ pub struct RttmSegment {
 }
 
 impl RttmSegment {
-    /// Parse a string into `RttmSegment`.
-    pub fn from_str(value: &str) -> Result<Self, RttmError> {
+    /// Returns start and end time in seconds.
+    pub fn timespan(&self) -> (f64, f64) {
+        (self.turn_onset, self.turn_onset + self.turn_duration)
+    }
+
+    /// Returns start and end time in milliseconds.
+    pub fn timespan_ms(&self) -> (i64, i64) {
+        (
+            (1000. * self.turn_onset).round() as i64,
+            (1000. * (self.turn_onset + self.turn_duration)).round() as i64
+        )
+    }
+
+    /// Returns duration as `std::time::Duration`.
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f64(self.turn_duration)
+    }
+
+    /// Returns duration in milliseconds.
+    pub fn milliseconds(&self) -> u128 {
+        self.duration().as_millis()
+    }
+}
+
+/// Parses a single space-delimited RTTM row into an `RttmSegment`.
+impl FromStr for RttmSegment {
+    type Err = RttmError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
         let mut segment = Self::default();
 
         let split = value.split(' ');
@@ -51,11 +81,13 @@ impl RttmSegment {
 
         Ok(segment)
     }
+}
 
-    /// Returns `RttmSegment` as a string that conforms to the standard
-    /// for writing to file.
-    pub fn to_string(&self) -> String {
-        format!("{} {} {} {} {} {} {} {} {} {}",
+/// Writes a segment as a single space-delimited RTTM row that conforms
+/// to the standard.
+impl fmt::Display for RttmSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {} {} {} {} {} {} {} {}",
             self.segment_type,
             self.file_id,
             self.channel_id,
@@ -68,27 +100,4 @@ impl RttmSegment {
             self.signal_lookahead_time,
         )
     }
-
-    /// Returns start and end time in seconds.
-    pub fn timespan(&self) -> (f64, f64) {
-        (self.turn_onset, self.turn_onset + self.turn_duration)
-    }
-
-    /// Returns start and end time in milliseconds.
-    pub fn timespan_ms(&self) -> (i64, i64) {
-        (
-            (1000. * self.turn_onset).round() as i64,
-            (1000. * (self.turn_onset + self.turn_duration)).round() as i64
-        )
-    }
-
-    /// Returns duration as `std::time::Duration`.
-    pub fn duration(&self) -> Duration {
-        Duration::from_secs_f64(self.turn_duration)
-    }
-
-    /// Returns duration in milliseconds.
-    pub fn milliseconds(&self) -> u128 {
-        self.duration().as_millis()
-    }
 }
\ No newline at end of file
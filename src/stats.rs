@@ -0,0 +1,140 @@
+//! Per-speaker and global conversational statistics for an `Rttm`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::Rttm;
+
+/// Statistics for a single speaker, computed across all of their turns.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpeakerStats {
+    /// Total speaking time in seconds.
+    pub total_duration: f64,
+    /// Number of turns.
+    pub num_turns: usize,
+    /// Mean turn duration in seconds.
+    pub mean_duration: f64,
+    /// Median turn duration in seconds.
+    pub median_duration: f64,
+    /// Longest single turn duration in seconds.
+    pub longest_duration: f64,
+    /// Share of total speech across all speakers, in percent.
+    pub share_percent: f64,
+}
+
+/// The full conversational profile of an `Rttm`: per-speaker statistics keyed
+/// by speaker name plus document-wide aggregates.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RttmStats {
+    /// Per-speaker statistics, sorted by speaker name.
+    pub speakers: BTreeMap<String, SpeakerStats>,
+    /// Total speaking time across all speakers in seconds.
+    pub total_duration: f64,
+    /// Total number of turns.
+    pub total_turns: usize,
+    /// Number of unique speakers.
+    pub num_speakers: usize,
+    /// Speaker-change rate over the recording span, in turns per minute.
+    pub speaker_change_rate: f64,
+}
+
+/// Returns the median of a non-empty slice of durations. Assumes `values` is
+/// already sorted ascending.
+fn median_sorted(values: &[f64]) -> f64 {
+    match values.len() {
+        0 => 0.0,
+        n if n % 2 == 1 => values[n / 2],
+        n => (values[n / 2 - 1] + values[n / 2]) / 2.0,
+    }
+}
+
+impl Rttm {
+    /// Computes per-speaker and global statistics. Turn durations are grouped
+    /// per speaker in one pass over the segments; the total speaking time
+    /// comes from [`duration_total`](Rttm::duration_total) and the recording
+    /// span from a further pass.
+    pub fn stats(&self) -> RttmStats {
+        let total_duration = self.duration_total();
+        let total_turns = self.segments().len();
+
+        // Collect turn durations per speaker.
+        let mut durations: BTreeMap<&str, Vec<f64>> = BTreeMap::new();
+        for seg in self.iter() {
+            durations.entry(seg.speaker_name.as_str())
+                .or_default()
+                .push(seg.turn_duration);
+        }
+
+        let mut speakers = BTreeMap::new();
+        for (speaker, mut turns) in durations {
+            turns.sort_by(|a, b| a.total_cmp(b));
+            let total = turns.iter().sum::<f64>();
+            let num_turns = turns.len();
+            let mean = if num_turns > 0 { total / num_turns as f64 } else { 0.0 };
+            let longest = turns.iter().cloned().fold(0.0_f64, f64::max);
+            let share = if total_duration > 0.0 {
+                100.0 * total / total_duration
+            } else {
+                0.0
+            };
+            speakers.insert(speaker.to_owned(), SpeakerStats {
+                total_duration: total,
+                num_turns,
+                mean_duration: mean,
+                median_duration: median_sorted(&turns),
+                longest_duration: longest,
+                share_percent: share,
+            });
+        }
+
+        // Speaker-change rate is measured against the wall-clock span of the
+        // recording, from the earliest onset to the latest offset.
+        let span = self.iter()
+            .map(|seg| seg.timespan())
+            .fold(None::<(f64, f64)>, |acc, (start, end)| match acc {
+                Some((min, max)) => Some((min.min(start), max.max(end))),
+                None => Some((start, end)),
+            })
+            .map(|(min, max)| max - min)
+            .unwrap_or(0.0);
+        let speaker_change_rate = if span > 0.0 {
+            total_turns as f64 / (span / 60.0)
+        } else {
+            0.0
+        };
+
+        RttmStats {
+            num_speakers: speakers.len(),
+            speakers,
+            total_duration,
+            total_turns,
+            speaker_change_rate,
+        }
+    }
+}
+
+/// Prints a sorted per-speaker table followed by the document aggregates.
+impl fmt::Display for RttmStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<20} {:>8} {:>10} {:>8} {:>8} {:>8} {:>7}",
+            "speaker", "turns", "total(s)", "mean(s)", "med(s)", "max(s)", "share%")?;
+        for (speaker, s) in &self.speakers {
+            writeln!(f, "{:<20} {:>8} {:>10.2} {:>8.2} {:>8.2} {:>8.2} {:>6.1}%",
+                speaker,
+                s.num_turns,
+                s.total_duration,
+                s.mean_duration,
+                s.median_duration,
+                s.longest_duration,
+                s.share_percent)?;
+        }
+        writeln!(f, "{:-<72}", "")?;
+        writeln!(f, "{} speakers, {} turns, {:.2}s total speech, {:.2} turns/min",
+            self.num_speakers,
+            self.total_turns,
+            self.total_duration,
+            self.speaker_change_rate)
+    }
+}
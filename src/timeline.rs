@@ -0,0 +1,94 @@
+//! Interval arithmetic over the turns of an `Rttm`.
+//!
+//! Turns are treated as half-open `[onset, onset + duration)` intervals.
+//! Boundary comparisons go through the integer millisecond span from
+//! [`timespan_ms`](crate::RttmSegment::timespan_ms) to avoid floating-point
+//! equality surprises at shared boundaries.
+
+use crate::{Rttm, RttmSegment};
+
+impl Rttm {
+    /// Returns every pair of segments whose timespans intersect, as references
+    /// into the owning `Rttm`. Cross-speaker pairs are co-speech; same-speaker
+    /// pairs indicate an overlapping annotation.
+    ///
+    /// Found with a single interval sweep after sorting by onset, so the cost
+    /// is `O(n log n + k)` for `k` reported pairs.
+    pub fn overlaps(&self) -> Vec<(&RttmSegment, &RttmSegment)> {
+        let mut order = (0..self.segments().len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| self.segments()[i].timespan_ms().0);
+
+        let mut pairs = Vec::new();
+        // Indices of segments still "open" at the current sweep position.
+        let mut active: Vec<usize> = Vec::new();
+        for &i in &order {
+            let (start, _) = self.segments()[i].timespan_ms();
+            // Drop intervals that have ended at or before this onset.
+            active.retain(|&j| self.segments()[j].timespan_ms().1 > start);
+            for &j in &active {
+                pairs.push((&self.segments()[j], &self.segments()[i]));
+            }
+            active.push(i);
+        }
+        pairs
+    }
+
+    /// Returns silence regions `(start, end)` in seconds between consecutive
+    /// turns, i.e. spans the union of all turns does not cover. Segments are
+    /// swept once in onset order.
+    pub fn gaps(&self) -> Vec<(f64, f64)> {
+        let mut order = (0..self.segments().len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| self.segments()[i].timespan_ms().0);
+
+        let mut gaps = Vec::new();
+        let mut cursor: Option<(f64, i64)> = None; // (end_secs, end_ms)
+        for &i in &order {
+            let seg = &self.segments()[i];
+            let (start, end) = seg.timespan();
+            let (start_ms, end_ms) = seg.timespan_ms();
+            if let Some((cur_end, cur_end_ms)) = cursor {
+                if start_ms > cur_end_ms {
+                    gaps.push((cur_end, start));
+                }
+                if end_ms > cur_end_ms {
+                    cursor = Some((end, end_ms));
+                }
+            } else {
+                cursor = Some((end, end_ms));
+            }
+        }
+        gaps
+    }
+
+    /// Collapses turns of the same speaker separated by less than `max_gap`
+    /// seconds into a single segment spanning from the first onset to the last
+    /// offset. When `same_speaker` is `false`, adjacency ignores the speaker
+    /// name and consecutive turns are merged on the gap alone, keeping the
+    /// first turn's speaker. Segments are processed in onset order.
+    pub fn merge_adjacent(&self, max_gap: f64, same_speaker: bool) -> Rttm {
+        let max_gap_ms = (max_gap * 1000.0).round() as i64;
+
+        let mut order = (0..self.segments().len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| self.segments()[i].timespan_ms().0);
+
+        let mut merged: Vec<RttmSegment> = Vec::new();
+        for &i in &order {
+            let seg = &self.segments()[i];
+            if let Some(last) = merged.last_mut() {
+                let (_, last_end_ms) = last.timespan_ms();
+                let (start_ms, _) = seg.timespan_ms();
+                let gap = start_ms - last_end_ms;
+                let speaker_ok = !same_speaker || last.speaker_name == seg.speaker_name;
+                if speaker_ok && gap < max_gap_ms {
+                    let new_end = (seg.turn_onset + seg.turn_duration)
+                        .max(last.turn_onset + last.turn_duration);
+                    last.turn_duration = new_end - last.turn_onset;
+                    continue;
+                }
+            }
+            merged.push(seg.clone());
+        }
+
+        Rttm::from_segments(merged)
+    }
+}